@@ -0,0 +1,53 @@
+use std::str::FromStr;
+
+/// A Solana cluster the tool can target. `Custom` carries an arbitrary RPC URL
+/// so the same flows can hit a private validator or a localnet without editing
+/// any constants.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Cluster {
+    Mainnet,
+    Devnet,
+    Testnet,
+    Localnet,
+    Custom(String),
+}
+
+impl Cluster {
+    /// The RPC endpoint to connect an `RpcClient` to.
+    pub fn rpc_url(&self) -> String {
+        match self {
+            Cluster::Mainnet => "https://api.mainnet-beta.solana.com".to_string(),
+            Cluster::Devnet => "https://api.devnet.solana.com".to_string(),
+            Cluster::Testnet => "https://api.testnet.solana.com".to_string(),
+            Cluster::Localnet => "http://127.0.0.1:8899".to_string(),
+            Cluster::Custom(url) => url.clone(),
+        }
+    }
+
+    /// The `?cluster=` suffix to append to an explorer.solana.com link. Mainnet
+    /// is the explorer default, so it needs no suffix.
+    pub fn explorer_suffix(&self) -> &str {
+        match self {
+            Cluster::Mainnet => "",
+            Cluster::Devnet => "?cluster=devnet",
+            Cluster::Testnet => "?cluster=testnet",
+            Cluster::Localnet => "?cluster=custom&customUrl=http://127.0.0.1:8899",
+            Cluster::Custom(_) => "?cluster=custom",
+        }
+    }
+}
+
+impl FromStr for Cluster {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "m" | "mainnet" | "mainnet-beta" => Ok(Cluster::Mainnet),
+            "d" | "devnet" => Ok(Cluster::Devnet),
+            "t" | "testnet" => Ok(Cluster::Testnet),
+            "l" | "localnet" | "localhost" => Ok(Cluster::Localnet),
+            other if other.starts_with("http") => Ok(Cluster::Custom(s.to_string())),
+            other => Err(format!("Unknown cluster: {}", other)),
+        }
+    }
+}
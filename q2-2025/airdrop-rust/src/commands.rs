@@ -0,0 +1,147 @@
+use solana_client::rpc_client::RpcClient;
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    system_instruction::transfer,
+};
+use solana_sdk::{
+    message::Message,
+    signature::{Keypair, Signer},
+};
+use solana_program::system_program;
+
+use crate::config::Config;
+use crate::programs::Turbin3_prereq::{Turbin3PrereqProgram, UpdateArgs};
+use crate::signer::load_signer;
+use crate::tx;
+use crate::wallet::Wallet;
+
+/// Generate a brand new keypair and print it in the `solana-keygen` byte-array
+/// format so it can be pasted into a JSON wallet file.
+pub fn keygen() {
+    let kp = Keypair::new();
+    println!("You've generated a new Solana wallet: {}", kp.pubkey());
+    println!();
+    println!("To save your wallet copy and paste the following into a JSON file:");
+    println!("{:?}", kp.to_bytes());
+}
+
+/// Request an airdrop of `lamports` to the configured keypair.
+pub fn airdrop(config: &Config, lamports: u64) {
+    let signer = load_signer(&config.keypair_path);
+    let client = RpcClient::new(config.rpc_url());
+
+    match client.request_airdrop(&signer.pubkey(), lamports) {
+        Ok(s) => {
+            println!("Success! Check out your TX here:");
+            println!("{}", config.explorer_tx(&s.to_string()));
+        }
+        Err(e) => println!("Oops, something went wrong: {}", e),
+    }
+}
+
+/// Transfer `lamports` from the configured keypair to `to`. When `lamports` is
+/// `None` the whole balance minus the network fee is swept out.
+pub fn transfer_sol(config: &Config, to: &Pubkey, lamports: Option<u64>) {
+    let signer = load_signer(&config.keypair_path);
+    let rpc_client = RpcClient::new(config.rpc_url());
+
+    let recent_blockhash = rpc_client
+        .get_latest_blockhash()
+        .expect("Failed to get recent blockhash");
+
+    let amount = match lamports {
+        Some(amount) => amount,
+        None => {
+            let balance = rpc_client
+                .get_balance(&signer.pubkey())
+                .expect("Failed to get balance");
+            let message = Message::new_with_blockhash(
+                &[transfer(&signer.pubkey(), to, balance)],
+                Some(&signer.pubkey()),
+                &recent_blockhash,
+            );
+            let fee = rpc_client
+                .get_fee_for_message(&message)
+                .expect("Failed to get fee calculator");
+            balance - fee
+        }
+    };
+
+    let signature = tx::execute(
+        &rpc_client,
+        &signer.pubkey(),
+        &[signer.as_ref()],
+        &[transfer(&signer.pubkey(), to, amount)],
+        config.dry_run,
+    )
+    .expect("Failed to send transaction");
+
+    if let Some(signature) = signature {
+        println!("Success! Check out your TX here: {}", config.explorer_tx(&signature.to_string()));
+    }
+}
+
+/// Submit the Turbin3 prereq `update` instruction for the given GitHub handle.
+pub fn enroll(config: &Config, github: &str) {
+    let rpc_client = RpcClient::new(config.rpc_url());
+    let signer = load_signer(&config.keypair_path);
+
+    let prereq =
+        Turbin3PrereqProgram::derive_program_address(&[b"prereq", signer.pubkey().to_bytes().as_ref()]);
+
+    let args = UpdateArgs { github: github.as_bytes().to_vec() };
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .expect("Failed to get recent blockhash");
+
+    // The generated program only hands back a built `Transaction`, so pull the
+    // single `update` instruction out of it and drive it through `tx::execute`.
+    // That way `enroll` gets the same blockhash re-signing, pre-flight
+    // simulation and `--dry-run` handling as `transfer_sol`.
+    let built = Turbin3PrereqProgram::update(
+        &[&signer.pubkey(), &prereq, &system_program::id()],
+        &args,
+        Some(&signer.pubkey()),
+        &[signer.as_ref()],
+        blockhash,
+    );
+    let compiled = &built.message.instructions[0];
+    let instruction = Instruction {
+        program_id: built.message.account_keys[compiled.program_id_index as usize],
+        accounts: vec![
+            AccountMeta::new(signer.pubkey(), true),
+            AccountMeta::new(prereq, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: compiled.data.clone(),
+    };
+
+    let signature = tx::execute(
+        &rpc_client,
+        &signer.pubkey(),
+        &[signer.as_ref()],
+        &[instruction],
+        config.dry_run,
+    )
+    .expect("Failed to send transaction");
+
+    if let Some(signature) = signature {
+        println!("Success! Check out your TX here: {}", config.explorer_tx(&signature.to_string()));
+    }
+}
+
+/// Recover a keypair from a BIP39 mnemonic and print it in the byte-array
+/// wallet format, ready to be redirected into a JSON file.
+pub fn wallet_import(phrase: &str, passphrase: &str, account_index: u32) {
+    let wallet = Wallet::from_mnemonic(phrase, passphrase).expect("Couldn't parse mnemonic");
+    let kp = wallet.derive(account_index);
+    eprintln!("Recovered wallet: {}", kp.pubkey());
+    println!("{:?}", kp.to_bytes());
+}
+
+/// Print the public key of a keypair file so it can be shared or verified.
+pub fn wallet_export(keypair_path: &str) {
+    let signer = load_signer(keypair_path);
+    println!("Public key: {}", signer.pubkey());
+}
@@ -0,0 +1,48 @@
+use crate::cluster::Cluster;
+
+/// Runtime configuration for the prereq flows: which cluster to target and
+/// where to read the default keypair from. Keeping these in one struct means
+/// the airdrop/transfer/enroll code never hardcodes an RPC URL or a filename.
+pub struct Config {
+    pub cluster: Cluster,
+    pub keypair_path: String,
+    /// When set, instruction flows simulate and print the result but never
+    /// broadcast, so fees are never spent.
+    pub dry_run: bool,
+}
+
+impl Config {
+    pub fn new(cluster: Cluster, keypair_path: impl Into<String>) -> Self {
+        Self {
+            cluster,
+            keypair_path: keypair_path.into(),
+            dry_run: false,
+        }
+    }
+
+    /// Toggle dry-run mode, returning `self` for builder-style chaining.
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// The RPC endpoint for the configured cluster.
+    pub fn rpc_url(&self) -> String {
+        self.cluster.rpc_url()
+    }
+
+    /// Build an explorer link for a signature on the configured cluster.
+    pub fn explorer_tx(&self, signature: &str) -> String {
+        format!(
+            "https://explorer.solana.com/tx/{}{}",
+            signature,
+            self.cluster.explorer_suffix()
+        )
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self::new(Cluster::Devnet, "dev-wallet.json")
+    }
+}
@@ -1,4 +1,10 @@
-mod programs;
+pub mod cluster;
+pub mod commands;
+pub mod config;
+pub mod programs;
+pub mod signer;
+pub mod tx;
+pub mod wallet;
 
 #[cfg(test)]
 mod tests {
@@ -10,8 +16,9 @@ mod tests {
     use solana_program::{pubkey::Pubkey, system_instruction::transfer, system_program};
     use std::str::FromStr;
     use crate::programs::Turbin3_prereq::{Turbin3PrereqProgram, CompleteArgs, UpdateArgs};
-	
-    const RPC_URL: &str = "https://api.devnet.solana.com";
+    use crate::wallet::Wallet;
+    use crate::cluster::Cluster;
+    use crate::config::Config;
 
     #[test]
     fn keygen() {
@@ -25,17 +32,20 @@ mod tests {
 
     #[test]
     fn airdrop() {
+	// Load our cluster + keypair path from config
+	let config = Config::default();
+
 	// Import our keypair
-	let keypair = read_keypair_file("dev-wallet.json").expect("Couldn't find wallet file");
+	let keypair = read_keypair_file(&config.keypair_path).expect("Couldn't find wallet file");
 
-	// Connected to Solana Devnet RPC Client
-	let client = RpcClient::new(RPC_URL);
+	// Connect to the configured cluster's RPC Client
+	let client = RpcClient::new(config.rpc_url());
 
 	// We're going to claim 2 devnet SOL tokens (2 billion lamports)
 	match client.request_airdrop(&keypair.pubkey(), 2_000_000_000u64) {
 		Ok(s) => {
 		  println!("Success! Check out your TX here:");
-		  println!("https://explorer.solana.com/tx/{}?cluster=devnet", s.to_string());
+		  println!("{}", config.explorer_tx(&s.to_string()));
 		}
 		Err(e) => println!("Oops, something went wrong: {}", e.to_string())
 	};
@@ -44,8 +54,11 @@ mod tests {
 
     #[test]
     fn transfer_sol() {
+	// Load our cluster + keypair path from config
+	let config = Config::default();
+
 	// Import our keypair
-	let keypair = read_keypair_file("dev-wallet.json").expect("Couldn't find wallet file");
+	let keypair = read_keypair_file(&config.keypair_path).expect("Couldn't find wallet file");
 
 	// With the imported Keypair, we can sign a new message
 	let pubkey = keypair.pubkey();
@@ -62,8 +75,8 @@ mod tests {
 	// Define our Turbin3 public key
 	let to_pubkey = Pubkey::from_str("ArCugaYbHumHTiwP9ArA5L2vHNgWrcVPuGSchYXhh9is").unwrap();
 
-	// Create a Solana devnet connection
-	let rpc_client = RpcClient::new(RPC_URL);
+	// Create a connection to the configured cluster
+	let rpc_client = RpcClient::new(config.rpc_url());
 
 	// Get recent blockhash
 	let recent_blockhash = rpc_client.get_latest_blockhash().expect("Failed to get recent blockhash");
@@ -89,17 +102,20 @@ mod tests {
 		.expect("Failed to send transaction");
 
 	// Print our transaction out
-	println!("Success! Check out your TX here: https://explorer.solana.com/tx/{}/?cluster=devnet", signature);
+	println!("Success! Check out your TX here: {}", config.explorer_tx(&signature.to_string()));
     }
 
 
     #[test]
     fn enroll(){
-	// Create a Solana devnet connection
-	let rpc_client = RpcClient::new(RPC_URL);
+	// Target devnet with the Turbin3 wallet
+	let config = Config::new(Cluster::Devnet, "Turbin3-wallet.json");
+
+	// Create a connection to the configured cluster
+	let rpc_client = RpcClient::new(config.rpc_url());
 
 	// Let's define our accounts
-	let signer = read_keypair_file("Turbin3-wallet.json").expect("Couldn't find wallet file");
+	let signer = read_keypair_file(&config.keypair_path).expect("Couldn't find wallet file");
 
 	let prereq = Turbin3PrereqProgram::derive_program_address(&[b"prereq", signer.pubkey().to_bytes().as_ref()]);
 
@@ -116,7 +132,23 @@ mod tests {
 	let signature = rpc_client.send_and_confirm_transaction(&transaction).expect("Failed to send transaction");
 
 	// Print our transaction out
-	println!("Success! Check out your TX here: https://explorer.solana.com/tx/{}?cluster=devnet", signature);
+	println!("Success! Check out your TX here: {}", config.explorer_tx(&signature.to_string()));
+    }
+
+    #[test]
+    fn mnemonic_wallet() {
+	// Back a wallet up as 12 words, recover it, and derive the first account
+	let phrase = Wallet::generate_mnemonic(12).expect("12 is a supported word count");
+	println!("Your recovery phrase is:");
+	println!("{}", phrase);
+
+	let wallet = Wallet::from_mnemonic(&phrase, "").expect("Couldn't parse mnemonic");
+	let kp = wallet.derive(0);
+	println!("Derived wallet at m/44'/501'/0'/0': {}", kp.pubkey().to_string());
+
+	// Recovering the same phrase must derive the same keypair
+	let recovered = Wallet::from_mnemonic(&phrase, "").expect("Couldn't parse mnemonic");
+	assert_eq!(kp.pubkey(), recovered.derive(0).pubkey());
     }
 
     #[test]
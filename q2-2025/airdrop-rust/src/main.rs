@@ -0,0 +1,109 @@
+use std::str::FromStr;
+
+use clap::{Parser, Subcommand};
+use solana_program::pubkey::Pubkey;
+
+use airdrop_rust::cluster::Cluster;
+use airdrop_rust::commands;
+use airdrop_rust::config::Config;
+
+/// Turbin3 prereq tooling: generate wallets, request airdrops, move SOL and
+/// enroll, all against a configurable cluster.
+#[derive(Parser)]
+#[command(name = "airdrop", version, about)]
+struct Cli {
+    /// Signer location: a keypair JSON file path or a `usb://` Ledger URI.
+    #[arg(long, global = true, default_value = "dev-wallet.json")]
+    keypair: String,
+
+    /// Cluster to target: m/d/t/l or a full custom RPC URL.
+    #[arg(long, global = true, default_value = "devnet")]
+    cluster: String,
+
+    /// Simulate and print the result without broadcasting any transaction.
+    #[arg(long, global = true)]
+    dry_run: bool,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Generate a brand new keypair.
+    Keygen,
+    /// Request an airdrop of `amount` SOL to the keypair.
+    Airdrop {
+        #[arg(default_value_t = 2.0)]
+        amount: f64,
+    },
+    /// Transfer SOL to a recipient; omit `--amount` to sweep the whole balance.
+    Transfer {
+        /// Recipient public key.
+        to: String,
+        /// Amount of SOL to send; defaults to the full balance minus fees.
+        #[arg(long)]
+        amount: Option<f64>,
+    },
+    /// Submit the Turbin3 prereq update for a GitHub handle.
+    Enroll {
+        github: String,
+    },
+    /// Import or export a wallet.
+    Wallet {
+        #[command(subcommand)]
+        action: WalletAction,
+    },
+}
+
+#[derive(Subcommand)]
+enum WalletAction {
+    /// Recover a keypair from a BIP39 mnemonic phrase.
+    Import {
+        /// The space-separated mnemonic phrase.
+        phrase: String,
+        /// Optional BIP39 passphrase.
+        #[arg(long, default_value = "")]
+        passphrase: String,
+        /// BIP44 account index (`m/44'/501'/{account}'/0'`).
+        #[arg(long, default_value_t = 0)]
+        account: u32,
+    },
+    /// Print the public key of a keypair file.
+    Export,
+}
+
+const LAMPORTS_PER_SOL: f64 = 1_000_000_000.0;
+
+fn sol_to_lamports(sol: f64) -> u64 {
+    (sol * LAMPORTS_PER_SOL) as u64
+}
+
+fn main() {
+    let cli = Cli::parse();
+
+    let cluster = Cluster::from_str(&cli.cluster).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        std::process::exit(1);
+    });
+    let config = Config::new(cluster, cli.keypair.clone()).with_dry_run(cli.dry_run);
+
+    match cli.command {
+        Command::Keygen => commands::keygen(),
+        Command::Airdrop { amount } => commands::airdrop(&config, sol_to_lamports(amount)),
+        Command::Transfer { to, amount } => {
+            let to = Pubkey::from_str(&to).unwrap_or_else(|_| {
+                eprintln!("Invalid recipient public key: {}", to);
+                std::process::exit(1);
+            });
+            commands::transfer_sol(&config, &to, amount.map(sol_to_lamports));
+        }
+        Command::Enroll { github } => commands::enroll(&config, &github),
+        Command::Wallet { action } => match action {
+            WalletAction::Import { phrase, passphrase, account } => {
+                commands::wallet_import(&phrase, &passphrase, account)
+            }
+            WalletAction::Export => commands::wallet_export(&cli.keypair),
+        },
+    }
+}
@@ -0,0 +1,49 @@
+use solana_remote_wallet::locator::Locator;
+use solana_remote_wallet::remote_keypair::generate_remote_keypair;
+use solana_remote_wallet::remote_wallet::maybe_wallet_manager;
+use solana_sdk::derivation_path::DerivationPath;
+use solana_sdk::signature::{read_keypair_file, Signer};
+
+/// Build a boxed signer from a location string. A plain path loads a local
+/// `Keypair` from a JSON file; a `usb://` URI (e.g. `usb://ledger?key=0`)
+/// resolves to a `RemoteKeypair` backed by a connected Ledger, so a private key
+/// never has to touch disk. Either flavour can be handed to any flow that takes
+/// a `&dyn Signer`.
+pub fn load_signer(location: &str) -> Box<dyn Signer> {
+    if location.starts_with("usb://") {
+        remote_signer(location)
+    } else {
+        Box::new(read_keypair_file(location).expect("Couldn't find wallet file"))
+    }
+}
+
+/// Resolve a `usb://` locator to a hardware-wallet signer using the
+/// `solana-remote-wallet` derivation-path URI scheme.
+fn remote_signer(uri: &str) -> Box<dyn Signer> {
+    let locator = Locator::new_from_path(uri).expect("Invalid remote wallet URI");
+
+    // The account lives in the `key=` query component (e.g. `usb://ledger?key=0/0`),
+    // not as an absolute BIP32 path, so parse that instead of feeding the whole URI
+    // to `from_absolute_path_str` and silently defaulting to account 0.
+    let derivation_path = match uri.split_once("key=") {
+        Some((_, key)) => {
+            DerivationPath::from_key_str(key).expect("Invalid derivation path in remote wallet URI")
+        }
+        None => DerivationPath::default(),
+    };
+
+    let manager = maybe_wallet_manager()
+        .expect("Failed to initialize hardware wallet manager")
+        .expect("No hardware wallet found; is the Ledger plugged in and unlocked?");
+
+    let keypair = generate_remote_keypair(
+        locator,
+        derivation_path,
+        &manager,
+        false,
+        "airdrop",
+    )
+    .expect("Failed to connect to remote wallet");
+
+    Box::new(keypair)
+}
@@ -0,0 +1,94 @@
+use solana_client::client_error::{ClientError, ClientErrorKind};
+use solana_client::rpc_client::RpcClient;
+use solana_client::rpc_response::RpcSimulateTransactionResult;
+use solana_program::instruction::Instruction;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Signature, Signer},
+    transaction::Transaction,
+};
+
+/// How many times `execute` re-signs with a fresh blockhash before giving up.
+const MAX_RETRIES: usize = 3;
+
+/// Build, sign and confirm a transaction, transparently recovering from an
+/// expired blockhash or an `AccountInUse` collision. On such an error we fetch
+/// a fresh blockhash, re-sign with the full `signers` slice and retry up to
+/// `MAX_RETRIES` times; any other error is returned immediately.
+pub fn execute(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    instructions: &[Instruction],
+    dry_run: bool,
+) -> Result<Option<Signature>, ClientError> {
+    let mut attempt = 0;
+    loop {
+        let blockhash = client.get_latest_blockhash()?;
+        let transaction =
+            Transaction::new_signed_with_payer(instructions, Some(payer), signers, blockhash);
+
+        // Preview compute usage, logs and any instruction error before we spend
+        // a single lamport. In dry-run mode that preview is the whole point, so
+        // bail out before broadcasting.
+        let simulation = simulate(client, &transaction)?;
+        if dry_run {
+            println!("Dry run: transaction was simulated but not broadcast.");
+            return Ok(None);
+        }
+
+        // Simulation already predicts failure: the network still charges the fee
+        // on a failed transaction, so bail out instead of broadcasting it.
+        if let Some(err) = simulation.err {
+            return Err(ClientErrorKind::Custom(format!(
+                "transaction simulation failed: {:?}",
+                err
+            ))
+            .into());
+        }
+
+        match client.send_and_confirm_transaction(&transaction) {
+            Ok(signature) => return Ok(Some(signature)),
+            Err(e) if attempt < MAX_RETRIES && is_retryable(&e) => {
+                attempt += 1;
+                eprintln!("Transaction failed ({}), re-signing and retrying ({}/{})", e, attempt, MAX_RETRIES);
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+/// Run a transaction through the RPC `simulateTransaction` endpoint and print a
+/// preview: compute units consumed, program logs, and any instruction error.
+/// The raw result is returned so callers can react to it programmatically.
+pub fn simulate(
+    client: &RpcClient,
+    transaction: &Transaction,
+) -> Result<RpcSimulateTransactionResult, ClientError> {
+    let result = client.simulate_transaction(transaction)?.value;
+
+    println!("--- simulation ---");
+    if let Some(units) = result.units_consumed {
+        println!("compute units consumed: {}", units);
+    }
+    if let Some(logs) = &result.logs {
+        for log in logs {
+            println!("  {}", log);
+        }
+    }
+    match &result.err {
+        Some(err) => println!("instruction error: {:?}", err),
+        None => println!("simulation ok"),
+    }
+
+    Ok(result)
+}
+
+/// Whether a client error is worth retrying with a fresh blockhash: either the
+/// blockhash expired or the accounts were momentarily in use.
+fn is_retryable(error: &ClientError) -> bool {
+    let message = error.to_string();
+    message.contains("AccountInUse")
+        || message.contains("Blockhash not found")
+        || message.contains("block height exceeded")
+}
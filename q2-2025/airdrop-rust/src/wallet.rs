@@ -0,0 +1,83 @@
+use bip39::Mnemonic;
+use hmac::{Hmac, Mac};
+use sha2::Sha512;
+use solana_sdk::signature::{keypair_from_seed, Keypair};
+
+type HmacSha512 = Hmac<Sha512>;
+
+/// A BIP39/SLIP-0010 seed wallet.
+///
+/// The wallet is anchored on a 64-byte seed derived from a BIP39 mnemonic so a
+/// user can back the whole thing up as 12 or 24 words instead of a raw byte
+/// array. Child `Keypair`s are derived on demand along the Solana-standard
+/// `m/44'/501'/{account}'/0'` path.
+pub struct Wallet {
+    seed: [u8; 64],
+}
+
+impl Wallet {
+    /// Generate a fresh mnemonic with the given word count (12 -> 128 bits of
+    /// entropy, 24 -> 256 bits). Returns the space-separated phrase to be
+    /// written down and later fed back into `from_mnemonic`. Only 12 and 24 are
+    /// supported; any other length is rejected rather than quietly downgraded.
+    pub fn generate_mnemonic(word_count: usize) -> Result<String, bip39::Error> {
+        let entropy_bits = match word_count {
+            12 => 128,
+            24 => 256,
+            _ => return Err(bip39::Error::BadWordCount(word_count)),
+        };
+        let mut entropy = vec![0u8; entropy_bits / 8];
+        getrandom::getrandom(&mut entropy).expect("Failed to gather entropy");
+        Ok(Mnemonic::from_entropy(&entropy)
+            .expect("Invalid entropy length")
+            .to_string())
+    }
+
+    /// Recover a wallet from a mnemonic phrase. The 64-byte seed is
+    /// `PBKDF2-HMAC-SHA512(mnemonic, "mnemonic" + passphrase, 2048)` exactly as
+    /// specified by BIP39; pass an empty `passphrase` for the common case.
+    pub fn from_mnemonic(phrase: &str, passphrase: &str) -> Result<Self, bip39::Error> {
+        let mnemonic = Mnemonic::parse(phrase)?;
+        Ok(Self {
+            seed: mnemonic.to_seed(passphrase),
+        })
+    }
+
+    /// Derive the keypair at `m/44'/501'/{account}'/0'` using SLIP-0010 ed25519
+    /// hardened derivation.
+    pub fn derive(&self, account_index: u32) -> Keypair {
+        let (mut key, mut chain_code) = master_key(&self.seed);
+        for index in [44u32, 501, account_index, 0] {
+            let (child_key, child_chain) = derive_hardened(&key, &chain_code, index);
+            key = child_key;
+            chain_code = child_chain;
+        }
+        keypair_from_seed(&key).expect("Failed to build keypair from derived scalar")
+    }
+}
+
+/// SLIP-0010 master key: `HMAC-SHA512("ed25519 seed", seed)`.
+fn master_key(seed: &[u8]) -> ([u8; 32], [u8; 32]) {
+    let mut mac = HmacSha512::new_from_slice(b"ed25519 seed").expect("HMAC accepts any key size");
+    mac.update(seed);
+    split(&mac.finalize().into_bytes())
+}
+
+/// One hardened SLIP-0010 step:
+/// `HMAC-SHA512(chain_code, 0x00 || key || ser32(index | 0x80000000))`.
+fn derive_hardened(key: &[u8; 32], chain_code: &[u8; 32], index: u32) -> ([u8; 32], [u8; 32]) {
+    let mut mac = HmacSha512::new_from_slice(chain_code).expect("HMAC accepts any key size");
+    mac.update(&[0u8]);
+    mac.update(key);
+    mac.update(&(index | 0x8000_0000).to_be_bytes());
+    split(&mac.finalize().into_bytes())
+}
+
+/// Split a 64-byte HMAC output into the child key and chain code halves.
+fn split(bytes: &[u8]) -> ([u8; 32], [u8; 32]) {
+    let mut key = [0u8; 32];
+    let mut chain_code = [0u8; 32];
+    key.copy_from_slice(&bytes[..32]);
+    chain_code.copy_from_slice(&bytes[32..]);
+    (key, chain_code)
+}